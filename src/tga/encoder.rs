@@ -15,6 +15,25 @@ enum EncoderError {
 
     /// Invalid TGA height.
     HeightInvalid(u32),
+
+    /// Palette has more than 256 entries.
+    PaletteTooLarge(usize),
+
+    /// The `indices` slice passed to [`TgaEncoder::encode_paletted`] was not
+    /// `width * height` bytes long.
+    IndicesLengthInvalid(usize),
+
+    /// A row passed to [`TgaRowEncoder::write_row`] was not
+    /// `width * bytes_per_pixel` bytes long.
+    RowLengthInvalid(usize),
+
+    /// [`TgaRowEncoder::write_row`] was called more times than the image's
+    /// `height`.
+    TooManyRows,
+
+    /// [`TgaRowEncoder::finish`] was called before `height` rows had been
+    /// written.
+    TooFewRows,
 }
 
 impl fmt::Display for EncoderError {
@@ -24,6 +43,23 @@ impl fmt::Display for EncoderError {
             EncoderError::HeightInvalid(s) => {
                 f.write_fmt(format_args!("Invalid TGA height: {}", s))
             }
+            EncoderError::PaletteTooLarge(len) => f.write_fmt(format_args!(
+                "TGA color map cannot hold more than 256 entries, got {}",
+                len
+            )),
+            EncoderError::IndicesLengthInvalid(len) => f.write_fmt(format_args!(
+                "TGA palette indices have an invalid length: {}",
+                len
+            )),
+            EncoderError::RowLengthInvalid(len) => {
+                f.write_fmt(format_args!("TGA row has an invalid length: {}", len))
+            }
+            EncoderError::TooManyRows => {
+                f.write_str("More rows were written than the image's height")
+            }
+            EncoderError::TooFewRows => {
+                f.write_str("finish() was called before all rows were written")
+            }
         }
     }
 }
@@ -36,15 +72,182 @@ impl From<EncoderError> for ImageError {
 
 impl error::Error for EncoderError {}
 
+/// The maximum number of identical (for a run packet) or literal (for a raw
+/// packet) pixels a single RLE packet can hold, per the TGA spec's 7-bit
+/// count field.
+const RLE_MAX_RUN: usize = 128;
+
+/// Size in bytes of a TGA header, as written by [`Header::write_to`].
+const HEADER_SIZE: u64 = 18;
+
+/// Size in bytes of the TGA 2.0 extension area, fixed by the spec.
+const EXTENSION_AREA_SIZE: u16 = 495;
+
+/// The 18-byte signature (including its trailing NUL) that marks a file as
+/// having a TGA 2.0 footer.
+const FOOTER_SIGNATURE: &[u8; 18] = b"TRUEVISION-XFILE.\0";
+
+/// A date/time stamp for the TGA 2.0 extension area.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct TgaTimestamp {
+    pub month: u16,
+    pub day: u16,
+    pub year: u16,
+    pub hour: u16,
+    pub minute: u16,
+    pub second: u16,
+}
+
+/// Chainable configuration for a [`TgaEncoder`].
+///
+/// Controls byte-level details of the written file that
+/// [`TgaEncoder::new`] otherwise leaves at plain-TGA defaults: whether the
+/// image data is run-length encoded, which corner the image origin is in,
+/// and an optional image ID string.
+#[derive(Debug, Clone)]
+pub struct TgaEncoderOptions {
+    use_rle: bool,
+    top_left_origin: bool,
+    image_id: Option<String>,
+    author_name: Option<String>,
+    comment: Option<String>,
+    timestamp: Option<TgaTimestamp>,
+    software_id: Option<String>,
+    software_version: Option<(u16, u8)>,
+    gamma: Option<f32>,
+}
+
+impl Default for TgaEncoderOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TgaEncoderOptions {
+    /// Creates options matching the defaults used by [`TgaEncoder::new`]:
+    /// raw (uncompressed) data, upper-left image origin, no image ID.
+    pub fn new() -> Self {
+        TgaEncoderOptions {
+            use_rle: false,
+            top_left_origin: true,
+            image_id: None,
+            author_name: None,
+            comment: None,
+            timestamp: None,
+            software_id: None,
+            software_version: None,
+            gamma: None,
+        }
+    }
+
+    /// Sets whether the image data is run-length encoded.
+    ///
+    /// RLE shrinks flat-color images considerably and is what most TGA
+    /// writers produce in practice; the default is raw (uncompressed) data.
+    pub fn with_rle(mut self, use_rle: bool) -> Self {
+        self.use_rle = use_rle;
+        self
+    }
+
+    /// Sets whether the image origin is the upper-left corner (`true`, the
+    /// default here) or the lower-left corner (`false`, the TGA spec's own
+    /// default and what many other tools expect).
+    ///
+    /// Only the header's origin bit changes: pixel rows are written in the
+    /// order they are given, so with a lower-left origin the first row
+    /// supplied becomes the bottom row of the image.
+    pub fn with_top_left_origin(mut self, top_left_origin: bool) -> Self {
+        self.top_left_origin = top_left_origin;
+        self
+    }
+
+    /// Attaches an image ID string, written immediately after the header.
+    /// Truncated to 255 bytes, the largest `id_length` can hold.
+    pub fn with_image_id(mut self, image_id: impl Into<String>) -> Self {
+        self.image_id = Some(image_id.into());
+        self
+    }
+
+    /// Sets the author name written to the TGA 2.0 extension area. Setting
+    /// any of the extension-area fields causes `encode` to write a TGA 2.0
+    /// footer; with none set, the output is a plain TGA 1.0 file.
+    pub fn with_author_name(mut self, author_name: impl Into<String>) -> Self {
+        self.author_name = Some(author_name.into());
+        self
+    }
+
+    /// Sets a single-line comment written to the TGA 2.0 extension area.
+    pub fn with_comment(mut self, comment: impl Into<String>) -> Self {
+        self.comment = Some(comment.into());
+        self
+    }
+
+    /// Sets the creation date/time written to the TGA 2.0 extension area.
+    pub fn with_timestamp(mut self, timestamp: TgaTimestamp) -> Self {
+        self.timestamp = Some(timestamp);
+        self
+    }
+
+    /// Sets the software name and version (`version` is e.g. `100` for
+    /// "1.00", `letter` an optional release letter such as `b'a'`) written
+    /// to the TGA 2.0 extension area.
+    pub fn with_software(
+        mut self,
+        software_id: impl Into<String>,
+        version: u16,
+        letter: u8,
+    ) -> Self {
+        self.software_id = Some(software_id.into());
+        self.software_version = Some((version, letter));
+        self
+    }
+
+    /// Sets the gamma value written to the TGA 2.0 extension area.
+    pub fn with_gamma(mut self, gamma: f32) -> Self {
+        self.gamma = Some(gamma);
+        self
+    }
+
+    fn id_bytes(&self) -> &[u8] {
+        match &self.image_id {
+            Some(id) => {
+                let bytes = id.as_bytes();
+                &bytes[..bytes.len().min(255)]
+            }
+            None => &[],
+        }
+    }
+
+    /// Whether any TGA 2.0 extension-area field has been set, in which case
+    /// `encode` writes a footer and extension area after the image data.
+    fn has_footer_metadata(&self) -> bool {
+        self.author_name.is_some()
+            || self.comment.is_some()
+            || self.timestamp.is_some()
+            || self.software_id.is_some()
+            || self.gamma.is_some()
+    }
+}
+
 /// TGA encoder.
 pub struct TgaEncoder<W: Write> {
     writer: W,
+    options: TgaEncoderOptions,
 }
 
 impl<W: Write> TgaEncoder<W> {
     /// Create a new encoder that writes its output to ```w```.
     pub fn new(w: W) -> TgaEncoder<W> {
-        TgaEncoder { writer: w }
+        TgaEncoder {
+            writer: w,
+            options: TgaEncoderOptions::new(),
+        }
+    }
+
+    /// Create a new encoder that writes its output to ```w```, configured
+    /// with ```options```.
+    pub fn new_with_options(w: W, options: TgaEncoderOptions) -> TgaEncoder<W> {
+        TgaEncoder { writer: w, options }
     }
 
     /// Encodes the image ```buf``` that has dimensions ```width```
@@ -59,25 +262,380 @@ impl<W: Write> TgaEncoder<W> {
         height: u32,
         color_type: ColorType,
     ) -> ImageResult<()> {
-        // Write out TGA header.
-        let header = Header::from_pixel_info(color_type, width, height)?;
+        let row_bytes = width as usize * usize::from(color_type.bytes_per_pixel());
+        let mut rows = self.encode_rows(width, height, color_type)?;
+        for row in buf.chunks(row_bytes.max(1)) {
+            rows.write_row(row)?;
+        }
+        rows.finish()
+    }
+
+    /// Begins a row-streaming encode: the header (and image ID, if any) are
+    /// written immediately, and the returned [`TgaRowEncoder`] accepts one
+    /// scanline at a time. This lets callers producing pixels on the fly
+    /// avoid holding the whole frame in memory, unlike `encode`, which needs
+    /// `buf` to hold every row up front.
+    ///
+    /// The dimensions of the image must be between 0 and 65535 (inclusive)
+    /// or an error will be returned.
+    pub fn encode_rows(
+        &mut self,
+        width: u32,
+        height: u32,
+        color_type: ColorType,
+    ) -> ImageResult<TgaRowEncoder<'_, W>> {
+        let header = Header::from_pixel_info(color_type, width, height, &self.options)?;
+        header.write_to(&mut self.writer)?;
+        let id_bytes = if width > 0 && height > 0 {
+            self.options.id_bytes()
+        } else {
+            &[]
+        };
+        self.writer.write_all(id_bytes)?;
+        let bytes_written = HEADER_SIZE + id_bytes.len() as u64;
+
+        let bytes_per_pixel = usize::from(color_type.bytes_per_pixel());
+        let row_bytes = width as usize * bytes_per_pixel;
+
+        // `Header::from_pixel_info` only commits to `height` rows of data
+        // when both dimensions are non-zero; mirror that here so `finish`
+        // doesn't demand rows that were never part of the image.
+        let committed_rows = if width > 0 && height > 0 { height } else { 0 };
+
+        Ok(TgaRowEncoder {
+            writer: &mut self.writer,
+            options: &self.options,
+            color_type,
+            bytes_per_pixel,
+            row_bytes,
+            height: committed_rows,
+            rows_written: 0,
+            bytes_written,
+            scratch: Vec::with_capacity(row_bytes),
+        })
+    }
+
+    /// Encodes a color-mapped (paletted) image: `indices` is one palette
+    /// index per pixel, and `palette` holds the color map entries
+    /// (`Rgb8` or `Rgba8`, whichever `palette_color_type` says) in index
+    /// order.
+    ///
+    /// Like the true-color path, indices are written run-length encoded if
+    /// the encoder's options enabled RLE. `indices` must be exactly
+    /// `width * height` bytes long. The dimensions of the image must be
+    /// between 0 and 65535 (inclusive), and the palette must not have more
+    /// than 256 entries, or an error will be returned.
+    pub fn encode_paletted(
+        mut self,
+        indices: &[u8],
+        width: u32,
+        height: u32,
+        palette: &[u8],
+        palette_color_type: ColorType,
+    ) -> ImageResult<()> {
+        let palette_bpp = usize::from(palette_color_type.bytes_per_pixel());
+        let has_alpha = match palette_color_type {
+            ColorType::Rgb8 => false,
+            ColorType::Rgba8 => true,
+            _ => {
+                return Err(ImageError::Unsupported(
+                    UnsupportedError::from_format_and_kind(
+                        ImageFormat::Tga.into(),
+                        UnsupportedErrorKind::Color(palette_color_type.into()),
+                    ),
+                ))
+            }
+        };
+
+        let palette_len = palette.len() / palette_bpp;
+        if palette_len > 256 {
+            return Err(EncoderError::PaletteTooLarge(palette_len).into());
+        }
+
+        let expected_indices_len = width as usize * height as usize;
+        if indices.len() != expected_indices_len {
+            return Err(EncoderError::IndicesLengthInvalid(indices.len()).into());
+        }
+
+        let header =
+            Header::from_palette_info(width, height, palette_len, has_alpha, &self.options)?;
         header.write_to(&mut self.writer)?;
+        let id_bytes = if width > 0 && height > 0 {
+            self.options.id_bytes()
+        } else {
+            &[]
+        };
+        self.writer.write_all(id_bytes)?;
+        let mut bytes_written = HEADER_SIZE + id_bytes.len() as u64;
 
-        // Write out Bgr(a)8 or L(a)8 image data.
-        let mut image = Vec::from(buf);
+        // Write out the color map, BGR(A)-swapped to match the true-color path.
+        // `Header::from_palette_info` only declares a color map when both
+        // dimensions are non-zero, so skip the write in lockstep with it.
+        if width > 0 && height > 0 {
+            let mut map = Vec::from(palette);
+            for chunk in map.chunks_mut(palette_bpp) {
+                chunk.swap(0, 2);
+            }
+            self.writer.write_all(&map)?;
+            bytes_written += map.len() as u64;
+        }
 
-        match color_type {
-            ColorType::Rgb8 | ColorType::Rgba8 => {
-                for chunk in image.chunks_mut(usize::from(color_type.bytes_per_pixel())) {
-                    chunk.swap(0, 2);
-                }
+        // Write out the index data.
+        let row_bytes = width as usize;
+
+        if self.options.use_rle {
+            for row in indices.chunks(row_bytes.max(1)) {
+                bytes_written += write_rle_scanline(&mut self.writer, row, 1)? as u64;
             }
-            _ => {}
+        } else {
+            self.writer.write_all(indices)?;
+            bytes_written += indices.len() as u64;
         }
 
-        self.writer.write_all(&image)?;
+        self.write_footer_if_configured(bytes_written)?;
+
         Ok(())
     }
+
+    /// Writes a TGA 2.0 footer and extension area after the image data, if
+    /// any extension-area field was configured via [`TgaEncoderOptions`].
+    /// `image_data_end` is the number of bytes written so far, which becomes
+    /// the extension area's offset from the start of the file.
+    fn write_footer_if_configured(&mut self, image_data_end: u64) -> io::Result<()> {
+        maybe_write_footer(&mut self.writer, &self.options, image_data_end)
+    }
+}
+
+/// Encodes a TGA image one scanline at a time, performing the BGR(A) channel
+/// swap into a small reusable scratch buffer rather than cloning the whole
+/// frame. Created by [`TgaEncoder::encode_rows`].
+pub struct TgaRowEncoder<'a, W: Write> {
+    writer: &'a mut W,
+    options: &'a TgaEncoderOptions,
+    color_type: ColorType,
+    bytes_per_pixel: usize,
+    row_bytes: usize,
+    height: u32,
+    rows_written: u32,
+    bytes_written: u64,
+    scratch: Vec<u8>,
+}
+
+impl<'a, W: Write> TgaRowEncoder<'a, W> {
+    /// Writes one scanline of pixel data, in the same color type and byte
+    /// order as `buf` in [`TgaEncoder::encode`] (rows are written in the
+    /// order supplied, top-to-bottom for the default upper-left origin; the
+    /// BGR swap happens internally). `row` must be
+    /// exactly `width * color_type.bytes_per_pixel()` bytes.
+    pub fn write_row(&mut self, row: &[u8]) -> ImageResult<()> {
+        if row.len() != self.row_bytes {
+            return Err(EncoderError::RowLengthInvalid(row.len()).into());
+        }
+        if self.rows_written >= self.height {
+            return Err(EncoderError::TooManyRows.into());
+        }
+
+        self.scratch.clear();
+        self.scratch.extend_from_slice(row);
+        swap_to_bgr(&mut self.scratch, self.color_type, self.bytes_per_pixel);
+
+        if self.options.use_rle {
+            self.bytes_written +=
+                write_rle_scanline(&mut *self.writer, &self.scratch, self.bytes_per_pixel)? as u64;
+        } else {
+            self.writer.write_all(&self.scratch)?;
+            self.bytes_written += self.scratch.len() as u64;
+        }
+
+        self.rows_written += 1;
+        Ok(())
+    }
+
+    /// Finishes the stream, writing the TGA 2.0 footer if configured.
+    ///
+    /// Returns [`EncoderError::TooFewRows`] if fewer than `height` rows were
+    /// written via [`write_row`](Self::write_row); the header above the
+    /// stream already committed to `height` rows of data, so finishing early
+    /// would leave behind a structurally invalid file.
+    pub fn finish(self) -> ImageResult<()> {
+        if self.rows_written != self.height {
+            return Err(EncoderError::TooFewRows.into());
+        }
+        maybe_write_footer(self.writer, self.options, self.bytes_written)?;
+        Ok(())
+    }
+}
+
+/// Writes a TGA 2.0 footer and extension area after the image data, if any
+/// extension-area field was configured via [`TgaEncoderOptions`].
+/// `image_data_end` is the number of bytes written so far, which becomes the
+/// extension area's offset from the start of the file.
+fn maybe_write_footer(
+    w: &mut dyn Write,
+    options: &TgaEncoderOptions,
+    image_data_end: u64,
+) -> io::Result<()> {
+    if !options.has_footer_metadata() {
+        return Ok(());
+    }
+
+    let extension_area_offset = u32::try_from(image_data_end).unwrap_or(u32::MAX);
+    write_extension_area(w, options)?;
+    write_footer(w, extension_area_offset)?;
+    Ok(())
+}
+
+/// Swaps the red and blue channels of `buf` in place so that `Rgb8`/`Rgba8`
+/// data matches the `Bgr8`/`Bgra8` order TGA stores on disk. Other color
+/// types are left untouched.
+fn swap_to_bgr(buf: &mut [u8], color_type: ColorType, bytes_per_pixel: usize) {
+    match color_type {
+        ColorType::Rgb8 | ColorType::Rgba8 => {
+            for chunk in buf.chunks_mut(bytes_per_pixel) {
+                chunk.swap(0, 2);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Writes a single scanline of `row` (already in its on-disk byte order, i.e.
+/// BGR(A) already swapped) as TGA RLE packets, per the TGA spec: a 1-byte
+/// header whose top bit selects a run packet (bit set) or a raw packet (bit
+/// clear), and whose low 7 bits hold `count - 1`. Runs are never allowed to
+/// span scanlines, so this is called once per row.
+/// Returns the number of bytes written (the packet headers plus their
+/// payloads), so callers can track the encoded file's length.
+fn write_rle_scanline(w: &mut dyn Write, row: &[u8], bytes_per_pixel: usize) -> io::Result<usize> {
+    if bytes_per_pixel == 0 || row.is_empty() {
+        return Ok(0);
+    }
+
+    let pixel_count = row.len() / bytes_per_pixel;
+    let pixel = |i: usize| &row[i * bytes_per_pixel..(i + 1) * bytes_per_pixel];
+
+    let mut bytes_written = 0;
+    let mut i = 0;
+    while i < pixel_count {
+        let mut run_len = 1;
+        while run_len < RLE_MAX_RUN && i + run_len < pixel_count && pixel(i + run_len) == pixel(i)
+        {
+            run_len += 1;
+        }
+
+        if run_len >= 2 {
+            w.write_u8(0x80 | (run_len - 1) as u8)?;
+            w.write_all(pixel(i))?;
+            bytes_written += 1 + bytes_per_pixel;
+            i += run_len;
+            continue;
+        }
+
+        // Accumulate a raw packet of literal pixels, stopping as soon as a
+        // run of >= 2 identical pixels starts (that run gets its own packet)
+        // or the raw packet hits its 128-pixel cap.
+        let start = i;
+        let mut count = 1;
+        i += 1;
+        while count < RLE_MAX_RUN && i < pixel_count {
+            let mut next_run = 1;
+            while next_run < RLE_MAX_RUN
+                && i + next_run < pixel_count
+                && pixel(i + next_run) == pixel(i)
+            {
+                next_run += 1;
+            }
+            if next_run >= 2 {
+                break;
+            }
+            count += 1;
+            i += 1;
+        }
+
+        w.write_u8((count - 1) as u8)?;
+        w.write_all(&row[start * bytes_per_pixel..(start + count) * bytes_per_pixel])?;
+        bytes_written += 1 + count * bytes_per_pixel;
+    }
+
+    Ok(bytes_written)
+}
+
+/// Writes a null-padded fixed-width ASCII field, truncating `s` if it is
+/// longer than `len` bytes.
+fn write_fixed_field(w: &mut dyn Write, s: &str, len: usize) -> io::Result<()> {
+    let bytes = s.as_bytes();
+    let n = bytes.len().min(len);
+    w.write_all(&bytes[..n])?;
+    for _ in n..len {
+        w.write_u8(0)?;
+    }
+    Ok(())
+}
+
+/// Converts a gamma value to the numerator/denominator pair the TGA 2.0
+/// extension area stores it as.
+fn gamma_to_fraction(gamma: f32) -> (u16, u16) {
+    const DENOMINATOR: u16 = 10_000;
+    let numerator = (gamma.max(0.0) * f32::from(DENOMINATOR)).round();
+    let numerator = if numerator > f32::from(u16::MAX) {
+        u16::MAX
+    } else {
+        numerator as u16
+    };
+    (numerator, DENOMINATOR)
+}
+
+/// Writes the 495-byte TGA 2.0 extension area.
+fn write_extension_area(w: &mut dyn Write, options: &TgaEncoderOptions) -> io::Result<()> {
+    w.write_u16::<LittleEndian>(EXTENSION_AREA_SIZE)?;
+    write_fixed_field(w, options.author_name.as_deref().unwrap_or(""), 41)?;
+
+    // Author comments: 4 lines of 81 bytes each; only the first is used.
+    write_fixed_field(w, options.comment.as_deref().unwrap_or(""), 81)?;
+    for _ in 0..3 {
+        write_fixed_field(w, "", 81)?;
+    }
+
+    let ts = options.timestamp.unwrap_or_default();
+    w.write_u16::<LittleEndian>(ts.month)?;
+    w.write_u16::<LittleEndian>(ts.day)?;
+    w.write_u16::<LittleEndian>(ts.year)?;
+    w.write_u16::<LittleEndian>(ts.hour)?;
+    w.write_u16::<LittleEndian>(ts.minute)?;
+    w.write_u16::<LittleEndian>(ts.second)?;
+
+    write_fixed_field(w, "", 41)?; // Job name/ID (unused).
+    w.write_u16::<LittleEndian>(0)?; // Job time: hours (unused).
+    w.write_u16::<LittleEndian>(0)?; // Job time: minutes (unused).
+    w.write_u16::<LittleEndian>(0)?; // Job time: seconds (unused).
+
+    write_fixed_field(w, options.software_id.as_deref().unwrap_or(""), 41)?;
+    let (version, version_letter) = options.software_version.unwrap_or((0, b' '));
+    w.write_u16::<LittleEndian>(version)?;
+    w.write_u8(version_letter)?;
+
+    w.write_all(&[0, 0, 0, 0])?; // Key color (unused).
+    w.write_u16::<LittleEndian>(0)?; // Pixel aspect ratio numerator (unused).
+    w.write_u16::<LittleEndian>(0)?; // Pixel aspect ratio denominator (unused).
+
+    let (gamma_numerator, gamma_denominator) = gamma_to_fraction(options.gamma.unwrap_or(0.0));
+    w.write_u16::<LittleEndian>(gamma_numerator)?;
+    w.write_u16::<LittleEndian>(gamma_denominator)?;
+
+    w.write_u32::<LittleEndian>(0)?; // Color correction offset (unused).
+    w.write_u32::<LittleEndian>(0)?; // Postage stamp offset (unused).
+    w.write_u32::<LittleEndian>(0)?; // Scan line offset (unused).
+    w.write_u8(0)?; // Attributes type: no alpha data.
+
+    Ok(())
+}
+
+/// Writes the 26-byte TGA 2.0 footer that points back at the extension area.
+fn write_footer(w: &mut dyn Write, extension_area_offset: u32) -> io::Result<()> {
+    w.write_u32::<LittleEndian>(extension_area_offset)?;
+    w.write_u32::<LittleEndian>(0)?; // Developer directory offset (unused).
+    w.write_all(FOOTER_SIGNATURE)?;
+    Ok(())
 }
 
 impl<W: Write> ImageEncoder for TgaEncoder<W> {
@@ -98,6 +656,7 @@ impl Header {
         color_type: ColorType,
         width: u32,
         height: u32,
+        options: &TgaEncoderOptions,
     ) -> ImageResult<Self> {
         let mut header = Self::default();
 
@@ -108,25 +667,75 @@ impl Header {
             header.image_height = u16::try_from(height)
                 .map_err(|_| ImageError::from(EncoderError::HeightInvalid(height)))?;
 
-            let (num_alpha_bits, other_channel_bits, image_type) = match color_type {
-                ColorType::Rgba8 | ColorType::Bgra8 => (8, 24, ImageType::RawTrueColor),
-                ColorType::Rgb8 | ColorType::Bgr8 => (0, 24, ImageType::RawTrueColor),
-                ColorType::La8 => (8, 8, ImageType::RawGrayScale),
-                ColorType::L8 => (0, 8, ImageType::RawGrayScale),
-                _ => {
-                    return Err(ImageError::Unsupported(
-                        UnsupportedError::from_format_and_kind(
-                            ImageFormat::Tga.into(),
-                            UnsupportedErrorKind::Color(color_type.into()),
-                        ),
-                    ))
-                }
-            };
-
-            header.image_type = image_type as u8;
+            let (num_alpha_bits, other_channel_bits, raw_image_type, rle_image_type) =
+                match color_type {
+                    ColorType::Rgba8 | ColorType::Bgra8 => {
+                        (8, 24, ImageType::RawTrueColor, ImageType::RleTrueColor)
+                    }
+                    ColorType::Rgb8 | ColorType::Bgr8 => {
+                        (0, 24, ImageType::RawTrueColor, ImageType::RleTrueColor)
+                    }
+                    ColorType::La8 => (8, 8, ImageType::RawGrayScale, ImageType::RleGrayScale),
+                    ColorType::L8 => (0, 8, ImageType::RawGrayScale, ImageType::RleGrayScale),
+                    _ => {
+                        return Err(ImageError::Unsupported(
+                            UnsupportedError::from_format_and_kind(
+                                ImageFormat::Tga.into(),
+                                UnsupportedErrorKind::Color(color_type.into()),
+                            ),
+                        ))
+                    }
+                };
+
+            header.image_type = if options.use_rle {
+                rle_image_type
+            } else {
+                raw_image_type
+            } as u8;
             header.pixel_depth = num_alpha_bits + other_channel_bits;
             header.image_desc = num_alpha_bits & ALPHA_BIT_MASK;
-            header.image_desc |= SCREEN_ORIGIN_BIT_MASK; // Upper left origin.
+            if options.top_left_origin {
+                header.image_desc |= SCREEN_ORIGIN_BIT_MASK;
+            }
+            header.id_length = options.id_bytes().len() as u8;
+        }
+
+        Ok(header)
+    }
+
+    /// Load the header with values for a color-mapped (paletted) image.
+    pub(crate) fn from_palette_info(
+        width: u32,
+        height: u32,
+        palette_len: usize,
+        palette_has_alpha: bool,
+        options: &TgaEncoderOptions,
+    ) -> ImageResult<Self> {
+        let mut header = Self::default();
+
+        if width > 0 && height > 0 {
+            header.image_width = u16::try_from(width)
+                .map_err(|_| ImageError::from(EncoderError::WidthInvalid(width)))?;
+
+            header.image_height = u16::try_from(height)
+                .map_err(|_| ImageError::from(EncoderError::HeightInvalid(height)))?;
+
+            header.image_type = if options.use_rle {
+                ImageType::RleColorMapped
+            } else {
+                ImageType::ColorMapped
+            } as u8;
+
+            header.map_type = 1;
+            header.map_origin = 0;
+            header.map_length = palette_len as u16;
+            header.map_entry_size = if palette_has_alpha { 32 } else { 24 };
+
+            header.pixel_depth = 8;
+            if options.top_left_origin {
+                header.image_desc |= SCREEN_ORIGIN_BIT_MASK;
+            }
+            header.id_length = options.id_bytes().len() as u8;
         }
 
         Ok(header)
@@ -152,7 +761,8 @@ impl Header {
 
 #[cfg(test)]
 mod tests {
-    use super::{EncoderError, TgaEncoder};
+    use super::{EncoderError, TgaEncoder, TgaEncoderOptions};
+    use crate::tga::header::ImageType;
     use crate::tga::TgaDecoder;
 
     use crate::color::ColorType;
@@ -178,6 +788,23 @@ mod tests {
         buf
     }
 
+    fn round_trip_rle_image(image: &[u8], width: u32, height: u32, c: ColorType) -> Vec<u8> {
+        let mut encoded_data = Vec::new();
+        {
+            let options = TgaEncoderOptions::new().with_rle(true);
+            let encoder = TgaEncoder::new_with_options(&mut encoded_data, options);
+            encoder
+                .encode(&image, width, height, c)
+                .expect("could not encode image");
+        }
+
+        let decoder = TgaDecoder::new(Cursor::new(&encoded_data)).expect("failed to decode");
+
+        let mut buf = vec![0; decoder.total_bytes() as usize];
+        decoder.read_image(&mut buf).expect("failed to decode");
+        buf
+    }
+
     #[test]
     fn test_image_width_too_large() {
         // TGA cannot encode images larger than 65,535×65,535
@@ -287,4 +914,362 @@ mod tests {
         let image = [0; 3 * 3 * 3]; // 3x3 pixels, 3 bytes per pixel
         let _decoded = round_trip_image(&image, 3, 3, ColorType::Rgb8);
     }
+
+    #[test]
+    fn round_trip_rle_flat_color() {
+        // A solid-color image is the best case for RLE: one big run per row.
+        let image = [9, 8, 7].repeat(16);
+        let decoded = round_trip_rle_image(&image, 4, 4, ColorType::Rgb8);
+        assert_eq!(decoded.as_slice(), image.as_slice());
+    }
+
+    #[test]
+    fn round_trip_rle_mixed_runs_and_literals() {
+        // Mix of runs (>= 2 identical pixels) and literal pixels within a
+        // single row, and across several rows, to exercise both packet types
+        // and the packet-boundary logic in `write_rle_scanline`.
+        #[rustfmt::skip]
+        let image: [u8; 8 * 3] = [
+            1, 1, 1,  1, 1, 1,  2, 2, 2,  3, 3, 3,
+            4, 4, 4,  5, 5, 5,  5, 5, 5,  5, 5, 5,
+        ];
+        let decoded = round_trip_rle_image(&image, 4, 2, ColorType::Rgb8);
+        assert_eq!(decoded.as_slice(), image.as_slice());
+    }
+
+    #[test]
+    fn round_trip_rle_gray() {
+        let image = [1, 1, 1, 2, 2, 3, 4, 4, 4, 4];
+        let decoded = round_trip_rle_image(&image, 10, 1, ColorType::L8);
+        assert_eq!(decoded.as_slice(), image.as_slice());
+    }
+
+    #[test]
+    fn paletted_writes_color_map_block() {
+        let indices = [0u8, 1, 2, 1];
+        let palette = [10, 20, 30, 40, 50, 60, 70, 80, 90]; // 3 RGB entries
+
+        let mut encoded = Vec::new();
+        let encoder = TgaEncoder::new(&mut encoded);
+        encoder
+            .encode_paletted(&indices, 4, 1, &palette, ColorType::Rgb8)
+            .expect("could not encode paletted image");
+
+        // Header: id_length, map_type, image_type
+        assert_eq!(encoded[0], 0); // id_length
+        assert_eq!(encoded[1], 1); // map_type
+        assert_eq!(encoded[2], 1); // image_type: ColorMapped
+        assert_eq!(&encoded[3..5], &[0, 0]); // map_origin
+        assert_eq!(&encoded[5..7], &[3, 0]); // map_length (LE)
+        assert_eq!(encoded[7], 24); // map_entry_size: no alpha
+
+        // Color map immediately follows the 18-byte header, BGR-swapped.
+        let map = &encoded[18..18 + 9];
+        assert_eq!(map, &[30, 20, 10, 60, 50, 40, 90, 80, 70]);
+
+        // Indices follow the color map verbatim (no RLE requested).
+        let data = &encoded[18 + 9..];
+        assert_eq!(data, &indices);
+    }
+
+    #[test]
+    fn paletted_rle_indices() {
+        let indices = [5u8, 5, 5, 2];
+        let palette = [0, 0, 0, 255, 255, 255];
+
+        let mut encoded = Vec::new();
+        let options = TgaEncoderOptions::new().with_rle(true);
+        let encoder = TgaEncoder::new_with_options(&mut encoded, options);
+        encoder
+            .encode_paletted(&indices, 4, 1, &palette, ColorType::Rgb8)
+            .expect("could not encode paletted image");
+
+        assert_eq!(encoded[2], ImageType::RleColorMapped as u8);
+
+        let data = &encoded[18 + 6..];
+        // Run packet of 3 identical indices, then a raw packet of 1.
+        assert_eq!(data, &[0x80 | 2, 5, 0x00, 2]);
+    }
+
+    #[test]
+    fn paletted_rejects_oversized_palette() {
+        let indices = [0u8; 4];
+        let palette = vec![0u8; 257 * 3];
+
+        let mut encoded = Vec::new();
+        let encoder = TgaEncoder::new(&mut encoded);
+        let result = encoder.encode_paletted(&indices, 4, 1, &palette, ColorType::Rgb8);
+        match result {
+            Err(ImageError::Encoding(err)) => {
+                let err = err
+                    .source()
+                    .unwrap()
+                    .downcast_ref::<EncoderError>()
+                    .unwrap();
+                assert_eq!(*err, EncoderError::PaletteTooLarge(257));
+            }
+            other => panic!(
+                "Encoding an oversized palette should return PaletteTooLarge, got {:?} instead",
+                other
+            ),
+        }
+    }
+
+    #[test]
+    fn paletted_rejects_mismatched_indices_length() {
+        let indices = [0u8; 3]; // should be 4 (width * height)
+        let palette = [0, 0, 0, 255, 255, 255];
+
+        let mut encoded = Vec::new();
+        let encoder = TgaEncoder::new(&mut encoded);
+        let result = encoder.encode_paletted(&indices, 4, 1, &palette, ColorType::Rgb8);
+        match result {
+            Err(ImageError::Encoding(err)) => {
+                let err = err
+                    .source()
+                    .unwrap()
+                    .downcast_ref::<EncoderError>()
+                    .unwrap();
+                assert_eq!(*err, EncoderError::IndicesLengthInvalid(3));
+            }
+            other => panic!(
+                "Encoding mismatched indices should return IndicesLengthInvalid, got {:?} instead",
+                other
+            ),
+        }
+    }
+
+    #[test]
+    fn paletted_zero_dimension_omits_color_map() {
+        // `Header::from_palette_info` declares no color map for a
+        // zero-dimension image; the body must not contain one either, or the
+        // header would understate what follows it in the stream.
+        let palette = [10, 20, 30, 40, 50, 60];
+
+        let mut encoded = Vec::new();
+        let encoder = TgaEncoder::new(&mut encoded);
+        encoder
+            .encode_paletted(&[], 0, 0, &palette, ColorType::Rgb8)
+            .expect("could not encode paletted image");
+
+        assert_eq!(encoded[1], 0); // map_type
+        assert_eq!(&encoded[5..7], &[0, 0]); // map_length
+        assert_eq!(encoded.len(), 18); // header only, no color map or indices
+    }
+
+    #[test]
+    fn options_write_image_id_and_bottom_left_origin() {
+        let image = [0, 1, 2];
+        let options = TgaEncoderOptions::new()
+            .with_top_left_origin(false)
+            .with_image_id("hi");
+
+        let mut encoded = Vec::new();
+        let encoder = TgaEncoder::new_with_options(&mut encoded, options);
+        encoder
+            .encode(&image, 1, 1, ColorType::Rgb8)
+            .expect("could not encode image");
+
+        assert_eq!(encoded[0], 2); // id_length
+        assert_eq!(encoded[17] & 0x20, 0); // SCREEN_ORIGIN_BIT_MASK cleared
+        assert_eq!(&encoded[18..20], b"hi");
+        assert_eq!(&encoded[20..23], &[2, 1, 0]); // pixel data, BGR-swapped
+    }
+
+    #[test]
+    fn zero_dimension_image_omits_id_bytes() {
+        // A 0-dimension image is valid per `encode`'s own doc comment, but
+        // `Header::from_pixel_info` leaves `id_length` at 0 for it; the image
+        // ID must not be written either, or the header would understate what
+        // follows it in the stream.
+        let options = TgaEncoderOptions::new().with_image_id("hi");
+        let mut encoded = Vec::new();
+        let mut encoder = TgaEncoder::new_with_options(&mut encoded, options);
+        let rows = encoder
+            .encode_rows(0, 0, ColorType::Rgb8)
+            .expect("could not start row encoder");
+        rows.finish().expect("could not finish row encoder");
+
+        assert_eq!(encoded[0], 0); // id_length
+        assert_eq!(encoded.len(), 18); // header only, no ID bytes appended
+    }
+
+    #[test]
+    fn encode_zero_width_nonzero_height_succeeds() {
+        // Zero in only one dimension is still a valid image per `encode`'s
+        // doc comment. `buf` is empty, so `write_row` is never called; the
+        // image's 0 committed rows must match `height` being treated as 0
+        // here, or `finish` would wrongly demand rows that don't exist.
+        let mut encoded = Vec::new();
+        let encoder = TgaEncoder::new(&mut encoded);
+        encoder
+            .encode(&[], 0, 3, ColorType::Rgb8)
+            .expect("zero-width image should encode successfully");
+
+        assert_eq!(encoded.len(), 18); // header only, no pixel data
+    }
+
+    #[test]
+    fn no_footer_by_default() {
+        let image = [0, 1, 2];
+        let mut encoded = Vec::new();
+        let encoder = TgaEncoder::new(&mut encoded);
+        encoder
+            .encode(&image, 1, 1, ColorType::Rgb8)
+            .expect("could not encode image");
+
+        // Plain TGA 1.0: header (18) + 3 bytes of pixel data, nothing more.
+        assert_eq!(encoded.len(), 18 + 3);
+    }
+
+    #[test]
+    fn writes_footer_and_extension_area_when_configured() {
+        use super::{TgaTimestamp, EXTENSION_AREA_SIZE, FOOTER_SIGNATURE};
+
+        let image = [0, 1, 2];
+        let options = TgaEncoderOptions::new()
+            .with_author_name("ferris")
+            .with_comment("rendered by the test suite")
+            .with_timestamp(TgaTimestamp {
+                month: 7,
+                day: 30,
+                year: 2026,
+                hour: 0,
+                minute: 0,
+                second: 0,
+            })
+            .with_software("image-rs", 100, b'a')
+            .with_gamma(2.2);
+
+        let mut encoded = Vec::new();
+        let encoder = TgaEncoder::new_with_options(&mut encoded, options);
+        encoder
+            .encode(&image, 1, 1, ColorType::Rgb8)
+            .expect("could not encode image");
+
+        let image_data_end = 18 + 3;
+        let extension_area_size = usize::from(EXTENSION_AREA_SIZE);
+
+        assert_eq!(
+            encoded.len(),
+            image_data_end + extension_area_size + 26,
+            "file should be image data + extension area + 26-byte footer"
+        );
+
+        // The extension area starts right after the image data and begins
+        // with its own size.
+        let extension_area = &encoded[image_data_end..image_data_end + extension_area_size];
+        assert_eq!(
+            u16::from_le_bytes([extension_area[0], extension_area[1]]),
+            EXTENSION_AREA_SIZE
+        );
+        assert!(extension_area[2..2 + 6].starts_with(b"ferris"));
+
+        // The footer points back at the extension area and ends with the
+        // TGA 2.0 signature.
+        let footer = &encoded[image_data_end + extension_area_size..];
+        assert_eq!(footer.len(), 26);
+        assert_eq!(
+            u32::from_le_bytes([footer[0], footer[1], footer[2], footer[3]]),
+            image_data_end as u32
+        );
+        assert_eq!(&footer[8..], FOOTER_SIGNATURE);
+    }
+
+    #[test]
+    fn encode_rows_matches_encode() {
+        let image = [0u8, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11]; // 2x2 RGB
+
+        let mut via_encode = Vec::new();
+        TgaEncoder::new(&mut via_encode)
+            .encode(&image, 2, 2, ColorType::Rgb8)
+            .expect("could not encode image");
+
+        let mut via_rows = Vec::new();
+        {
+            let mut encoder = TgaEncoder::new(&mut via_rows);
+            let mut rows = encoder
+                .encode_rows(2, 2, ColorType::Rgb8)
+                .expect("could not start row encoder");
+            rows.write_row(&image[0..6]).expect("could not write row");
+            rows.write_row(&image[6..12]).expect("could not write row");
+            rows.finish().expect("could not finish row encoder");
+        }
+
+        assert_eq!(via_rows, via_encode);
+    }
+
+    #[test]
+    fn write_row_rejects_wrong_length() {
+        let mut encoded = Vec::new();
+        let mut encoder = TgaEncoder::new(&mut encoded);
+        let mut rows = encoder
+            .encode_rows(2, 1, ColorType::Rgb8)
+            .expect("could not start row encoder");
+        let result = rows.write_row(&[0, 1, 2]);
+        match result {
+            Err(ImageError::Encoding(err)) => {
+                let err = err
+                    .source()
+                    .unwrap()
+                    .downcast_ref::<EncoderError>()
+                    .unwrap();
+                assert_eq!(*err, EncoderError::RowLengthInvalid(3));
+            }
+            other => panic!(
+                "Writing a mis-sized row should return RowLengthInvalid, got {:?} instead",
+                other
+            ),
+        }
+    }
+
+    #[test]
+    fn write_row_rejects_too_many_rows() {
+        let mut encoded = Vec::new();
+        let mut encoder = TgaEncoder::new(&mut encoded);
+        let mut rows = encoder
+            .encode_rows(1, 1, ColorType::Rgb8)
+            .expect("could not start row encoder");
+        rows.write_row(&[0, 1, 2]).expect("could not write row");
+        let result = rows.write_row(&[3, 4, 5]);
+        match result {
+            Err(ImageError::Encoding(err)) => {
+                let err = err
+                    .source()
+                    .unwrap()
+                    .downcast_ref::<EncoderError>()
+                    .unwrap();
+                assert_eq!(*err, EncoderError::TooManyRows);
+            }
+            other => panic!(
+                "Writing past the image height should return TooManyRows, got {:?} instead",
+                other
+            ),
+        }
+    }
+
+    #[test]
+    fn finish_rejects_too_few_rows() {
+        let mut encoded = Vec::new();
+        let mut encoder = TgaEncoder::new(&mut encoded);
+        let mut rows = encoder
+            .encode_rows(1, 2, ColorType::Rgb8)
+            .expect("could not start row encoder");
+        rows.write_row(&[0, 1, 2]).expect("could not write row");
+        let result = rows.finish();
+        match result {
+            Err(ImageError::Encoding(err)) => {
+                let err = err
+                    .source()
+                    .unwrap()
+                    .downcast_ref::<EncoderError>()
+                    .unwrap();
+                assert_eq!(*err, EncoderError::TooFewRows);
+            }
+            other => panic!(
+                "Finishing before all rows were written should return TooFewRows, got {:?} instead",
+                other
+            ),
+        }
+    }
 }